@@ -29,6 +29,10 @@ pub(crate) struct Cli {
 
     #[arg(long, short)]
     pub path: Option<PathBuf>,
+
+    /// disable colored output (also honors the `NO_COLOR` environment variable)
+    #[arg(long)]
+    pub no_color: bool,
 }
 
 #[derive(Subcommand)]
@@ -1,3 +1,5 @@
+use std::{env, ffi::OsString};
+
 use anyhow::Result;
 use args::{Commands, DoctorArgs};
 use clap::Parser;
@@ -13,6 +15,11 @@ mod workspace;
 
 fn main() -> Result<()> {
     let cli = Cli::parse();
+    if colors_disabled(cli.no_color, env::var_os("NO_COLOR")) {
+        console::set_colors_enabled(false);
+        console::set_colors_enabled_stderr(false);
+    }
+
     match &cli.command {
         Some(Commands::Doctor(DoctorArgs { .. })) => doctor::doctor(
             &cli.template_dir,
@@ -23,3 +30,22 @@ fn main() -> Result<()> {
         None => Ok(()),
     }
 }
+
+/// Colors are off if `--no-color` is passed or `NO_COLOR` is set to a non-empty value
+/// (see <https://no-color.org>)
+fn colors_disabled(no_color: bool, no_color_env: Option<OsString>) -> bool {
+    no_color || no_color_env.map_or(false, |value| !value.is_empty())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_colors_disabled() {
+        assert!(!colors_disabled(false, None));
+        assert!(!colors_disabled(false, Some(OsString::new())));
+        assert!(colors_disabled(false, Some(OsString::from("1"))));
+        assert!(colors_disabled(true, None));
+    }
+}